@@ -1,15 +1,84 @@
 use std::error::Error;
 
-// use bs58::decode::Error;
 use bs58::{decode, encode, Alphabet as AlphabetOriginal};
+use num_bigint::BigUint;
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyByteArray, PyBytes};
+use sha2::{Digest, Sha256};
 
 fn to_py_value_err<T: Error>(err: T) -> PyErr {
     PyValueError::new_err(err.to_string())
 }
 
+/// Base class for all decode errors raised by this module.
+create_exception!(based58, Base58DecodeError, PyValueError);
+/// Raised when a byte is not present in the given alphabet.
+create_exception!(based58, InvalidCharacter, Base58DecodeError);
+/// Raised when the destination buffer is too small to hold the decoded value.
+create_exception!(based58, BufferTooSmall, Base58DecodeError);
+/// Raised when a Base58Check checksum does not match the expected checksum.
+create_exception!(based58, InvalidChecksum, Base58DecodeError);
+/// Raised when a Base58Check version byte does not match the expected version.
+create_exception!(based58, InvalidVersion, Base58DecodeError);
+
+/// Build an `InvalidChecksum` with its `.actual`/`.expected` attributes set.
+/// Shared by `decode_error_to_py` (for `bs58::decode::Error::InvalidChecksum`,
+/// which nothing currently produces since no caller uses `bs58`'s own
+/// `with_check` builder anymore) and `b58decode_check`'s hand-rolled
+/// Base58Check verification.
+fn invalid_checksum_err(py: Python, actual: &[u8], expected: &[u8]) -> PyErr {
+    let pyerr = InvalidChecksum::new_err("invalid checksum");
+    let _ = pyerr.value(py).setattr("actual", PyBytes::new(py, actual));
+    let _ = pyerr
+        .value(py)
+        .setattr("expected", PyBytes::new(py, expected));
+    pyerr
+}
+
+/// Build an `InvalidVersion` with its `.actual`/`.expected` attributes set.
+/// See [`invalid_checksum_err`] for why this is shared rather than inlined.
+fn invalid_version_err(py: Python, actual: u8, expected: u8) -> PyErr {
+    let pyerr = InvalidVersion::new_err("invalid version byte");
+    let _ = pyerr.value(py).setattr("actual", actual);
+    let _ = pyerr.value(py).setattr("expected", expected);
+    pyerr
+}
+
+/// Map a [`decode::Error`] onto the richest matching exception in our
+/// hierarchy, attaching structured attributes so Python callers can inspect
+/// *why* and *where* a decode failed instead of parsing the message text.
+fn decode_error_to_py(err: decode::Error, py: Python) -> PyErr {
+    match err {
+        decode::Error::BufferTooSmall => {
+            BufferTooSmall::new_err("the destination buffer is too small to hold the decoded value")
+        }
+        decode::Error::InvalidCharacter { character, index } => {
+            let pyerr = InvalidCharacter::new_err(format!(
+                "invalid character {:?} at index {}",
+                character, index
+            ));
+            let _ = pyerr.value(py).setattr("character", character.to_string());
+            let _ = pyerr.value(py).setattr("index", index);
+            pyerr
+        }
+        decode::Error::NonAsciiCharacter { index } => {
+            let pyerr = InvalidCharacter::new_err(format!("non-ASCII byte at index {}", index));
+            let _ = pyerr.value(py).setattr("index", index);
+            pyerr
+        }
+        decode::Error::InvalidChecksum {
+            checksum,
+            expected_checksum,
+        } => invalid_checksum_err(py, &checksum, &expected_checksum),
+        decode::Error::InvalidVersion { ver, expected_ver } => {
+            invalid_version_err(py, ver, expected_ver)
+        }
+        other => Base58DecodeError::new_err(other.to_string()),
+    }
+}
+
 /// A collection of 58 ASCII characters used to encode data.
 ///
 /// Args:
@@ -23,10 +92,14 @@ fn to_py_value_err<T: Error>(err: T) -> PyErr {
 ///     b'`e\xe7\x9b\xba/x'
 ///     >>> b58encode(decoded, alphabet=alpha)
 ///     b'#ERRN)N RD'
+// `bs58::Alphabet`'s own `encode` table is a private field with no public
+// accessor, so we keep our own copy of the raw 58-byte table alongside it for
+// the handful of pyfunctions (`b58encode_int`/`b58decode_int`) that need to
+// index into it directly.
 #[pyclass]
 #[derive(Debug, Clone)]
 #[pyo3(text_signature = "(base)")]
-pub struct Alphabet(pub AlphabetOriginal);
+pub struct Alphabet(pub AlphabetOriginal, pub [u8; 58]);
 
 #[pymethods]
 impl Alphabet {
@@ -34,26 +107,26 @@ impl Alphabet {
     ///
     /// See <https://en.bitcoin.it/wiki/Base58Check_encoding#Base58_symbol_chart>
     #[classattr]
-    const BITCOIN: Self = Self(*AlphabetOriginal::BITCOIN);
+    const BITCOIN: Self = Self(*AlphabetOriginal::BITCOIN, *bs58::alphabet::BITCOIN);
     /// Monero's alphabet as defined in this forum post.
     ///
     /// See <https://forum.getmonero.org/4/academic-and-technical/221/creating-a-standard-for-physical-coins>
     #[classattr]
-    const MONERO: Self = Self(*AlphabetOriginal::MONERO);
+    const MONERO: Self = Self(*AlphabetOriginal::MONERO, *bs58::alphabet::MONERO);
     /// Ripple's alphabet as defined in their wiki.
     ///
     /// See <https://wiki.ripple.com/Encodings>
     #[classattr]
-    const RIPPLE: Self = Self(*AlphabetOriginal::RIPPLE);
+    const RIPPLE: Self = Self(*AlphabetOriginal::RIPPLE, *bs58::alphabet::RIPPLE);
     /// Flickr's alphabet for creating short urls from photo ids.
     ///
     /// See <https://www.flickr.com/groups/api/discuss/72157616713786392/>
     #[classattr]
-    const FLICKR: Self = Self(*AlphabetOriginal::FLICKR);
+    const FLICKR: Self = Self(*AlphabetOriginal::FLICKR, *bs58::alphabet::FLICKR);
     /// The default alphabet used if none is given. Currently is the
     /// [`BITCOIN`](Self::BITCOIN) alphabet.
     #[classattr]
-    const DEFAULT: Self = Self(*AlphabetOriginal::DEFAULT);
+    const DEFAULT: Self = Self(*AlphabetOriginal::DEFAULT, *bs58::alphabet::DEFAULT);
 
     #[new]
     pub fn new(base: &[u8]) -> PyResult<Self> {
@@ -65,7 +138,7 @@ impl Alphabet {
             ))
         })?;
         let underlying = AlphabetOriginal::new(sized_base).map_err(to_py_value_err)?;
-        Ok(Self(underlying))
+        Ok(Self(underlying, *sized_base))
     }
 
     pub fn __repr__(&self) -> String {
@@ -77,15 +150,85 @@ fn byte_vec_to_pybytes<'a>(v: &Vec<u8>, py: Python<'a>) -> &'a PyBytes {
     PyBytes::new(py, v.as_slice())
 }
 
+/// Either a `str` or `bytes` value accepted at the Python boundary.
+///
+/// Mirrors the `scrub_input` step of the reference Python `base58` package,
+/// which lets callers pass either type and have it normalized to bytes.
+#[derive(FromPyObject)]
+pub enum StrOrBytes<'a> {
+    #[pyo3(transparent, annotation = "str")]
+    Str(&'a str),
+    #[pyo3(transparent, annotation = "bytes")]
+    Bytes(&'a [u8]),
+}
+
+fn scrub_input(val: StrOrBytes) -> PyResult<&[u8]> {
+    match val {
+        StrOrBytes::Str(s) => {
+            if !s.is_ascii() {
+                return Err(PyValueError::new_err(
+                    "string argument should contain only ASCII characters",
+                ));
+            }
+            Ok(s.as_bytes())
+        }
+        StrOrBytes::Bytes(b) => Ok(b),
+    }
+}
+
+/// The longest checksum we can slice off a SHA256 digest.
+const MAX_CHECK_LEN: usize = 32;
+
+fn validate_check_len(check_len: usize) -> PyResult<()> {
+    if check_len == 0 || check_len > MAX_CHECK_LEN {
+        return Err(PyValueError::new_err(format!(
+            "check_len must be between 1 and {} inclusive, got {}",
+            MAX_CHECK_LEN, check_len
+        )));
+    }
+    Ok(())
+}
+
+/// Compute the Base58Check checksum over `payload`: `SHA256(SHA256(payload))`,
+/// truncated to `check_len` bytes.
+///
+/// `bs58`'s own `with_check`/`with_check_version` builders hardcode this to a
+/// fixed length of 4 bytes with no way to configure it, so this is done by
+/// hand to support other checksum lengths.
+fn base58check_checksum(payload: &[u8], check_len: usize) -> Vec<u8> {
+    let digest = Sha256::digest(payload);
+    let digest = Sha256::digest(digest);
+    digest[..check_len].to_vec()
+}
+
+/// Base58 output is ASCII for every alphabet this crate ships, but a
+/// caller-supplied `Alphabet` may contain non-ASCII bytes, so this is
+/// fallible rather than an `.expect()`.
+fn ascii_vec_to_string(v: Vec<u8>) -> PyResult<String> {
+    String::from_utf8(v).map_err(|_| {
+        PyValueError::new_err(
+            "cannot return as str: the alphabet produced non-ASCII output",
+        )
+    })
+}
+
+fn bytes_or_str(v: Vec<u8>, as_str: bool, py: Python) -> PyResult<PyObject> {
+    if as_str {
+        Ok(ascii_vec_to_string(v)?.into_py(py))
+    } else {
+        Ok(byte_vec_to_pybytes(&v, py).into_py(py))
+    }
+}
+
 /// Decode a base-58 value.
 ///
 /// Args:
-///     val (bytes): The bytes to decode.
+///     val (str | bytes): The value to decode.
 ///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
-///     
+///
 /// Returns:
 ///     bytes: The decoded value.
-///     
+///
 /// Example:
 ///     >>> from based58 import b58decode, Alphabet
 ///     >>> b58decode(b"he11owor1d")
@@ -95,23 +238,28 @@ fn byte_vec_to_pybytes<'a>(v: &Vec<u8>, py: Python<'a>) -> &'a PyBytes {
 ///
 #[pyfunction(alphabet = "Alphabet::BITCOIN")]
 #[pyo3(text_signature = "(val, alphabet)")]
-pub fn b58decode<'a>(val: &[u8], alphabet: Alphabet, py: Python<'a>) -> PyResult<&'a PyBytes> {
-    let byte_vec = decode(val)
+pub fn b58decode<'a>(
+    val: StrOrBytes,
+    alphabet: Alphabet,
+    py: Python<'a>,
+) -> PyResult<&'a PyBytes> {
+    let byte_vec = decode(scrub_input(val)?)
         .with_alphabet(&alphabet.0)
         .into_vec()
-        .map_err(to_py_value_err)?;
+        .map_err(|e| decode_error_to_py(e, py))?;
     Ok(byte_vec_to_pybytes(&byte_vec, py))
 }
 
 /// Encode bytes into base-58.
 ///
 /// Args:
-///     val (bytes): The bytes to encode.
+///     val (str | bytes): The value to encode.
 ///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
-///     
+///     as_str (bool, optional): Return a :class:`str` instead of :class:`bytes`. Defaults to False.
+///
 /// Returns:
-///     bytes: The encoded value.
-///     
+///     Union[bytes, str]: The encoded value.
+///
 /// Example:
 ///     >>> from based58 import b58encode, Alphabet
 ///     >>> b58encode(b"\x040^+$s\xf0X")
@@ -119,20 +267,21 @@ pub fn b58decode<'a>(val: &[u8], alphabet: Alphabet, py: Python<'a>) -> PyResult
 ///     >>> b58encode(b'`e\xe7\x9b\xba/x', Alphabet.RIPPLE)
 ///     b'he11owor1d'
 ///
-#[pyfunction(alphabet = "Alphabet::BITCOIN")]
-#[pyo3(text_signature = "(val, alphabet)")]
-pub fn b58encode<'a>(val: &[u8], alphabet: Alphabet, py: Python<'a>) -> &'a PyBytes {
-    let byte_vec = encode(val).with_alphabet(&alphabet.0).into_vec();
-    byte_vec_to_pybytes(&byte_vec, py)
+#[pyfunction(alphabet = "Alphabet::BITCOIN", as_str = "false")]
+#[pyo3(text_signature = "(val, alphabet, as_str = False)")]
+pub fn b58encode(val: StrOrBytes, alphabet: Alphabet, as_str: bool, py: Python) -> PyResult<PyObject> {
+    let byte_vec = encode(scrub_input(val)?).with_alphabet(&alphabet.0).into_vec();
+    bytes_or_str(byte_vec, as_str, py)
 }
 
 /// Decode and check checksum using the
 /// `Base58Check <https://en.bitcoin.it/wiki/Base58Check_encoding>`_ algorithm.
 ///
 /// Args:
-///     val (bytes): The bytes to decode.
+///     val (str | bytes): The value to decode.
 ///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
 ///     expected_ver (int, optional):  If provided, the version byte will be used in verification. Defaults to None.
+///     check_len (int, optional): The length in bytes of the trailing checksum. Defaults to 4, matching the Bitcoin Base58Check layout.
 ///
 /// Returns:
 ///     bytes: The decoded value.
@@ -142,64 +291,309 @@ pub fn b58encode<'a>(val: &[u8], alphabet: Alphabet, py: Python<'a>) -> &'a PyBy
 ///     >>> b58decode_check(b"PWEu9GGN")
 ///     b'-1'
 ///
-#[pyfunction(alphabet = "Alphabet::BITCOIN", expected_ver = "None")]
-#[pyo3(text_signature = "(val, alphabet, expected_ver = None)")]
+#[pyfunction(alphabet = "Alphabet::BITCOIN", expected_ver = "None", check_len = "4")]
+#[pyo3(text_signature = "(val, alphabet, expected_ver = None, check_len = 4)")]
 pub fn b58decode_check<'a>(
-    val: &[u8],
+    val: StrOrBytes,
     alphabet: Alphabet,
     expected_ver: Option<u8>,
+    check_len: usize,
     py: Python<'a>,
 ) -> PyResult<&'a PyBytes> {
-    let byte_vec = decode(val)
+    validate_check_len(check_len)?;
+    let full = decode(scrub_input(val)?)
         .with_alphabet(&alphabet.0)
-        .with_check(expected_ver)
         .into_vec()
-        .map_err(to_py_value_err)?;
-    Ok(byte_vec_to_pybytes(&byte_vec, py))
+        .map_err(|e| decode_error_to_py(e, py))?;
+    if full.len() < check_len {
+        return Err(Base58DecodeError::new_err(
+            "decoded value is shorter than check_len",
+        ));
+    }
+    let (payload, actual_checksum) = full.split_at(full.len() - check_len);
+    let expected_checksum = base58check_checksum(payload, check_len);
+    if actual_checksum != expected_checksum.as_slice() {
+        return Err(invalid_checksum_err(py, actual_checksum, &expected_checksum));
+    }
+    if let Some(ver) = expected_ver {
+        let actual_ver = payload.first().copied();
+        if actual_ver != Some(ver) {
+            return Err(invalid_version_err(py, actual_ver.unwrap_or_default(), ver));
+        }
+    }
+    Ok(byte_vec_to_pybytes(&payload.to_vec(), py))
 }
 
 /// Encode and check checksum using the
 /// `Base58Check <https://en.bitcoin.it/wiki/Base58Check_encoding>`_ algorithm.
 ///
 /// Args:
-///     val (bytes): The bytes to encode.
+///     val (str | bytes): The value to encode.
 ///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
 ///     expected_ver (int, optional):  If provided, the version byte will be used in verification. Defaults to None.
+///     check_len (int, optional): The length in bytes of the trailing checksum. Defaults to 4, matching the Bitcoin Base58Check layout.
+///     as_str (bool, optional): Return a :class:`str` instead of :class:`bytes`. Defaults to False.
 ///
 /// Returns:
-///     bytes: The encoded value.
+///     Union[bytes, str]: The encoded value.
 ///
 /// Example:
 ///     >>> from based58 import b58encode_check
 ///     >>> b58encode_check(b"`e\xe7\x9b\xba/x")
 ///     b'QuT57JNzzWTu7mW'
 ///
-#[pyfunction(alphabet = "Alphabet::BITCOIN", expected_ver = "None")]
-#[pyo3(text_signature = "(val, alphabet, expected_ver = None)")]
-pub fn b58encode_check<'a>(
-    val: &[u8],
+#[pyfunction(
+    alphabet = "Alphabet::BITCOIN",
+    expected_ver = "None",
+    check_len = "4",
+    as_str = "false"
+)]
+#[pyo3(text_signature = "(val, alphabet, expected_ver = None, check_len = 4, as_str = False)")]
+pub fn b58encode_check(
+    val: StrOrBytes,
     alphabet: Alphabet,
     expected_ver: Option<u8>,
+    check_len: usize,
+    as_str: bool,
+    py: Python,
+) -> PyResult<PyObject> {
+    validate_check_len(check_len)?;
+    let input = scrub_input(val)?;
+    let mut payload = Vec::with_capacity(input.len() + 1);
+    if let Some(ver) = expected_ver {
+        payload.push(ver);
+    }
+    payload.extend_from_slice(input);
+    let checksum = base58check_checksum(&payload, check_len);
+    payload.extend_from_slice(&checksum);
+    let byte_vec = encode(&payload).with_alphabet(&alphabet.0).into_vec();
+    bytes_or_str(byte_vec, as_str, py)
+}
+
+/// Encode a list of byte-strings into base-58, releasing the GIL.
+///
+/// Useful for workloads that encode large tables of keys or hashes, where
+/// per-element Python-to-Rust call overhead would otherwise dominate.
+///
+/// Args:
+///     vals (list[str | bytes]): The values to encode.
+///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
+///
+/// Returns:
+///     list[bytes]: The encoded values, in the same order as ``vals``.
+///
+/// Example:
+///     >>> from based58 import b58encode_batch
+///     >>> b58encode_batch([b"\x040^+$s\xf0X", b"`e\xe7\x9b\xba/x"])
+///     [b'he11owor1d', b'4essoAo1sd']
+///
+#[pyfunction(alphabet = "Alphabet::BITCOIN")]
+#[pyo3(text_signature = "(vals, alphabet = Alphabet.BITCOIN)")]
+pub fn b58encode_batch<'a>(
+    vals: Vec<StrOrBytes<'a>>,
+    alphabet: Alphabet,
     py: Python<'a>,
-) -> &'a PyBytes {
-    let builder = encode(val).with_alphabet(&alphabet.0);
-    let with_check = {
-        if let Some(ver) = expected_ver {
-            builder.with_check_version(ver)
+) -> PyResult<Vec<&'a PyBytes>> {
+    let scrubbed = vals
+        .into_iter()
+        .map(scrub_input)
+        .collect::<PyResult<Vec<_>>>()?;
+    let encoded = py.allow_threads(|| {
+        scrubbed
+            .iter()
+            .map(|v| encode(v).with_alphabet(&alphabet.0).into_vec())
+            .collect::<Vec<_>>()
+    });
+    Ok(encoded.iter().map(|v| byte_vec_to_pybytes(v, py)).collect())
+}
+
+/// Decode a list of base-58 values, releasing the GIL.
+///
+/// Args:
+///     vals (list[str | bytes]): The values to decode.
+///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
+///
+/// Returns:
+///     list[bytes]: The decoded values, in the same order as ``vals``.
+///
+/// Raises:
+///     Base58DecodeError: If any value fails to decode; ``.batch_index`` names the offending index.
+///
+/// Example:
+///     >>> from based58 import b58decode_batch
+///     >>> b58decode_batch([b"he11owor1d", b"4essoAo1sd"])
+///     [b'\x040^+$s\xf0X', b'`e\xe7\x9b\xba/x']
+///
+#[pyfunction(alphabet = "Alphabet::BITCOIN")]
+#[pyo3(text_signature = "(vals, alphabet = Alphabet.BITCOIN)")]
+pub fn b58decode_batch<'a>(
+    vals: Vec<StrOrBytes<'a>>,
+    alphabet: Alphabet,
+    py: Python<'a>,
+) -> PyResult<Vec<&'a PyBytes>> {
+    let scrubbed = vals
+        .into_iter()
+        .map(scrub_input)
+        .collect::<PyResult<Vec<_>>>()?;
+    let decoded = py.allow_threads(|| {
+        scrubbed
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                decode(v)
+                    .with_alphabet(&alphabet.0)
+                    .into_vec()
+                    .map_err(|e| (i, e))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    });
+    let decoded = decoded.map_err(|(i, e)| {
+        let pyerr = decode_error_to_py(e, py);
+        let _ = pyerr.value(py).setattr("batch_index", i);
+        pyerr
+    })?;
+    Ok(decoded.iter().map(|v| byte_vec_to_pybytes(v, py)).collect())
+}
+
+/// Decode a base-58 value into a pre-allocated buffer.
+///
+/// Unlike :func:`b58decode`, this writes the decoded bytes directly into an
+/// existing ``bytearray`` instead of allocating a fresh ``bytes`` object, so
+/// callers that decode many values (e.g. Solana account keys) can reuse one
+/// buffer and avoid per-call heap churn.
+///
+/// Args:
+///     val (bytes): The bytes to decode.
+///     buffer (bytearray): The buffer to decode into. Must be large enough to hold the decoded value.
+///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
+///
+/// Returns:
+///     int: The number of bytes written to ``buffer``.
+///
+/// Example:
+///     >>> from based58 import b58decode_into
+///     >>> buf = bytearray(8)
+///     >>> b58decode_into(b"he11owor1d", buf)
+///     8
+///
+#[pyfunction(alphabet = "Alphabet::BITCOIN")]
+#[pyo3(text_signature = "(val, buffer, alphabet = Alphabet.BITCOIN)")]
+pub fn b58decode_into(
+    val: &[u8],
+    buffer: &PyByteArray,
+    alphabet: Alphabet,
+    py: Python,
+) -> PyResult<usize> {
+    let out = unsafe { buffer.as_bytes_mut() };
+    decode(val)
+        .with_alphabet(&alphabet.0)
+        .onto(out)
+        .map_err(|e| decode_error_to_py(e, py))
+}
+
+/// Encode a non-negative integer into base-58.
+///
+/// This mirrors the leading-zero-agnostic ``b58encode_int`` helper from the
+/// reference Python ``base58`` package, which is handy for shortening
+/// numeric ids (e.g. Flickr photo ids) rather than encoding raw bytes.
+///
+/// Args:
+///     i (int): The non-negative integer to encode.
+///     default_one (bool, optional): Whether to return a single ``alphabet[0]`` character when ``i`` is zero, rather than an empty string. Defaults to True.
+///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
+///     as_str (bool, optional): Return a :class:`str` instead of :class:`bytes`. Defaults to False.
+///
+/// Returns:
+///     Union[bytes, str]: The encoded value.
+///
+/// Example:
+///     >>> from based58 import b58encode_int
+///     >>> b58encode_int(5000)
+///     b'2VD'
+///
+#[pyfunction(default_one = "true", alphabet = "Alphabet::BITCOIN", as_str = "false")]
+#[pyo3(text_signature = "(i, default_one = True, alphabet = Alphabet.BITCOIN, as_str = False)")]
+pub fn b58encode_int(
+    i: BigUint,
+    default_one: bool,
+    alphabet: Alphabet,
+    as_str: bool,
+    py: Python,
+) -> PyResult<PyObject> {
+    if i == BigUint::from(0u8) {
+        let out = if default_one {
+            vec![alphabet.1[0]]
         } else {
-            builder.with_check()
-        }
-    };
-    let byte_vec = with_check.into_vec();
-    byte_vec_to_pybytes(&byte_vec, py)
+            Vec::new()
+        };
+        return bytes_or_str(out, as_str, py);
+    }
+    let fifty_eight = BigUint::from(58u8);
+    let mut n = i;
+    let mut out = Vec::new();
+    while n > BigUint::from(0u8) {
+        let idx = (&n % &fifty_eight)
+            .to_u32_digits()
+            .first()
+            .copied()
+            .unwrap_or(0) as usize;
+        out.push(alphabet.1[idx]);
+        n /= &fifty_eight;
+    }
+    out.reverse();
+    bytes_or_str(out, as_str, py)
+}
+
+/// Decode a base-58 value into a non-negative integer.
+///
+/// Args:
+///     v (bytes): The base-58 encoded bytes to decode.
+///     alphabet (Alphabet, optional): The encoding alphabet. Defaults to :attr:`Alphabet.BITCOIN`.
+///
+/// Returns:
+///     int: The decoded value.
+///
+/// Example:
+///     >>> from based58 import b58decode_int
+///     >>> b58decode_int(b"2VD")
+///     5000
+///
+#[pyfunction(alphabet = "Alphabet::BITCOIN")]
+#[pyo3(text_signature = "(v, alphabet = Alphabet.BITCOIN)")]
+pub fn b58decode_int(v: &[u8], alphabet: Alphabet, py: Python) -> PyResult<BigUint> {
+    let fifty_eight = BigUint::from(58u8);
+    let mut acc = BigUint::from(0u8);
+    for (index, &c) in v.iter().enumerate() {
+        let idx = alphabet.1.iter().position(|&b| b == c).ok_or_else(|| {
+            let pyerr = InvalidCharacter::new_err(format!(
+                "invalid character {:?} at index {}",
+                c as char, index
+            ));
+            let _ = pyerr.value(py).setattr("character", (c as char).to_string());
+            let _ = pyerr.value(py).setattr("index", index);
+            pyerr
+        })?;
+        acc = acc * &fifty_eight + BigUint::from(idx as u32);
+    }
+    Ok(acc)
 }
 
 #[pymodule]
-fn based58(_py: Python, m: &PyModule) -> PyResult<()> {
+fn based58(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(b58decode, m)?)?;
     m.add_function(wrap_pyfunction!(b58encode, m)?)?;
     m.add_function(wrap_pyfunction!(b58decode_check, m)?)?;
     m.add_function(wrap_pyfunction!(b58encode_check, m)?)?;
+    m.add_function(wrap_pyfunction!(b58decode_into, m)?)?;
+    m.add_function(wrap_pyfunction!(b58encode_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(b58decode_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(b58encode_int, m)?)?;
+    m.add_function(wrap_pyfunction!(b58decode_int, m)?)?;
     m.add_class::<Alphabet>()?;
+    m.add("Base58DecodeError", py.get_type::<Base58DecodeError>())?;
+    m.add("InvalidCharacter", py.get_type::<InvalidCharacter>())?;
+    m.add("BufferTooSmall", py.get_type::<BufferTooSmall>())?;
+    m.add("InvalidChecksum", py.get_type::<InvalidChecksum>())?;
+    m.add("InvalidVersion", py.get_type::<InvalidVersion>())?;
     Ok(())
 }